@@ -48,6 +48,13 @@ class FileAccessError(LoglyException):
     pass
 
 
+class StreamWriteError(LoglyException):
+    """
+    Exception raised when there is an error writing to a stream added with add_stream.
+    """
+    pass
+
+
 class InvalidConfigError(LoglyException):
     """
     Exception raised for invalid Logly configuration.
@@ -16,16 +16,173 @@ along with Logly. If not, see <https://opensource.org/licenses/MIT>.
 """
 
 
+import codecs
+import functools
 import os
+import random
+import sys
+import time
+import traceback
+import warnings
+from collections import OrderedDict, deque
 from colorama import Fore, Style, init
 from datetime import datetime
 import re
 
-from logly.exception import FilePathNotFoundException, FileAccessError, FileCreationError, LoglyException
+from logly.exception import FilePathNotFoundException, FileAccessError, FileCreationError, LoglyException, \
+    InvalidConfigError, InvalidLogLevelError, StreamWriteError
 
 init(autoreset=True)
 
 
+class _CaptureStream:
+    """
+    File-like proxy that turns every complete line written to it into a log message.
+    """
+
+    def __init__(self, logly, level, original):
+        """
+        Initialize a _CaptureStream.
+
+        Parameters:
+        - logly (Logly): The Logly instance that receives the captured lines.
+        - level (str): Log level used for the captured lines.
+        - original (object): The stream being replaced, restored on release.
+        """
+        self.logly = logly
+        self.level = level
+        self.original = original
+        self._pending = ""
+
+    def write(self, text):
+        """
+        Buffer text and log each complete line.
+
+        Parameters:
+        - text (str): The text written to the stream.
+
+        Returns:
+        - int: Number of characters written.
+        """
+        self._pending += text
+        while "\n" in self._pending:
+            line, self._pending = self._pending.split("\n", 1)
+            self.logly.log_function(self.level, line)
+        return len(text)
+
+    def writelines(self, lines):
+        """
+        Write each of the given strings, so they are captured like write calls.
+
+        Parameters:
+        - lines (iterable): The strings to write.
+        """
+        for line in lines:
+            self.write(line)
+
+    def flush(self):
+        """
+        Do nothing: a partial line stays buffered until it is completed or the capture is released.
+        """
+
+    def release(self):
+        """
+        Log any buffered partial line, called when the capture is released.
+        """
+        if self._pending:
+            line, self._pending = self._pending, ""
+            self.logly.log_function(self.level, line)
+
+    def __getattr__(self, name):
+        """
+        Delegate any other attribute (isatty, encoding, fileno, ...) to the original stream.
+        """
+        return getattr(self.original, name)
+
+
+class MemoryBuffer:
+    """
+    Stream that keeps the most recent log messages in memory, see Logly.add_memory_buffer.
+    """
+
+    def __init__(self, capacity, logly):
+        """
+        Initialize a MemoryBuffer.
+
+        Parameters:
+        - capacity (int): Maximum number of log messages kept; older ones are discarded.
+        - logly (Logly): The logger writing to the buffer; its current record separator is stripped from each message.
+        """
+        self.logly = logly
+        self.messages = deque(maxlen=capacity)
+
+    def write(self, text):
+        """
+        Store one log message.
+
+        Parameters:
+        - text (str): The log message, followed by the record separator.
+        """
+        separator = self.logly.record_separator
+        if text.endswith(separator):
+            text = text[:-len(separator)]
+        self.messages.append(text)
+
+    def dump(self):
+        """
+        Get the stored log messages.
+
+        Returns:
+        - list: The stored log messages, oldest first.
+        """
+        return list(self.messages)
+
+    def clear(self):
+        """
+        Discard the stored log messages.
+        """
+        self.messages.clear()
+
+
+class _Catcher:
+    """
+    Decorator and context manager returned by Logly.catch.
+    """
+
+    def __init__(self, logly, level, reraise, kwargs):
+        """
+        Initialize a _Catcher.
+
+        Parameters:
+        - logly (Logly): The Logly instance that logs the caught exceptions.
+        - level (str): Log level used for the caught exceptions.
+        - reraise (bool): Whether to raise the exception again after logging it.
+        - kwargs (dict): Any other option accepted by log_function (color, log_to_file, file_path, ...).
+        """
+        self.logly = logly
+        self.level = level
+        self.reraise = reraise
+        self.kwargs = kwargs
+
+    def __enter__(self):
+        return self
+
+    def __exit__(self, exc_type, exc, tb):
+        if exc is None or not issubclass(exc_type, Exception):
+            return False
+        summary = "".join(traceback.format_exception_only(exc_type, exc)).strip()
+        details = self.logly.format_traceback(exc_type, exc, tb)
+        self.logly.log_function(self.level, "Caught exception", f"{summary}\n{details}", **self.kwargs)
+        return not self.reraise
+
+    def __call__(self, function):
+        @functools.wraps(function)
+        def wrapper(*args, **kwargs):
+            with self:
+                return function(*args, **kwargs)
+        return wrapper
+
+
 class Logly:
     """
     Logly: A simple logging utility.
@@ -33,6 +190,7 @@ class Logly:
     Attributes:
     - COLOR_MAP (dict): Mapping of log levels to color codes.
     - COLOR (class): Color constants for log messages.
+    - LEVEL_SEVERITY (dict): Mapping of log levels to their severity, used to compare levels.
     - DEFAULT_MAX_FILE_SIZE_MB (int): Default maximum file size in megabytes.
 
     Methods:
@@ -43,12 +201,47 @@ class Logly:
     - enable_file_logging: Enable logging to a file.
     - set_default_file_path: Set default file path.
     - set_default_max_file_size: Set default maximum file size.
+    - set_level_aliases: Set alternative names for log levels.
+    - register_level: Register a custom log level.
+    - set_max_line_bytes: Set the maximum length of a line written to a log file.
+    - set_record_separator: Set the separator written after each log message in files and streams.
+    - set_console_encoding: Set the encoding used for console output.
+    - set_redaction: Mask sensitive keys and values in log messages.
+    - set_level, get_level: Set / get the minimum level of the messages that are logged.
+    - is_enabled: Check whether messages of a level would be logged.
+    - set_sample_rate: Log only a fraction of the messages below a given level.
+    - set_debug_sample_rate: Log a fraction of the DEBUG messages even when the minimum level skips them.
+    - add_stream: Add a file-like object that receives every log message.
+    - remove_stream: Remove a file-like object added with add_stream.
+    - add_memory_buffer: Keep the most recent log messages in memory.
+    - add_filter: Add a callback that can drop or change log messages.
+    - remove_filter: Remove a callback added with add_filter.
+    - capture_stdout, capture_stderr: Redirect sys.stdout / sys.stderr writes into the logger.
+    - release_stdout, release_stderr: Restore sys.stdout / sys.stderr.
     - get_current_datetime: Get current date and time as a formatted string.
+    - level_severity: Get the severity of a log level.
     - remove_color_codes: Remove ANSI color codes from text.
+    - truncate_line: Cut a line to a maximum number of bytes.
+    - level_colors: Get the current mapping of log levels to color codes.
+    - set_level_color: Set the color used for a single log level.
+    - total_bytes_written: Get the total number of bytes written to log files.
+    - metrics: Get the metric counters as a dict.
+    - reset_metrics: Reset all metric counters to zero.
+    - set_measure_latency: Enable or disable measuring how long each log call takes.
+    - latency_stats: Get percentiles of the measured log call durations.
+    - _relative_time: Internal method to get the time elapsed since the previous log message as a formatted string.
+    - _format_message: Internal method to build the text of a log message.
     - _log: Internal method to log a message.
     - log_function: Log a message with exception handling.
     - info, warn, error, debug, critical, fatal, trace: Log messages with different levels.
     - log: Log a message with the INFO level.
+    - log_throttled, info_throttled, warn_throttled: Log a message at most once per interval.
+    - log_once, warn_once: Log a message only the first time it is seen.
+    - log_batch: Log several messages in one call.
+    - expect: Log an error message when a condition is false.
+    - exception: Log an error message with the traceback of the exception being handled.
+    - format_traceback: Format an exception and its traceback as indented lines.
+    - catch: Decorator / context manager that logs exceptions with their traceback.
     """
 
     COLOR_MAP = {
@@ -60,6 +253,17 @@ class Logly:
         "LOG": Fore.GREEN  # Added "LOG" level color
     }
 
+    LEVEL_SEVERITY = {
+        "TRACE": 5,
+        "DEBUG": 10,
+        "INFO": 20,
+        "LOG": 20,
+        "WARNING": 30,
+        "ERROR": 40,
+        "CRITICAL": 50,
+        "FATAL": 50
+    }
+
     # Define color constants
     class COLOR:
         BLUE = Fore.BLUE
@@ -72,8 +276,12 @@ class Logly:
 
     DEFAULT_MAX_FILE_SIZE_MB = 100  # 100MB
     DEFAULT_COLOR_ENABLED = True  # Add a class attribute for controlling default Colorama behavior
+    REDACTED = "***"  # Replaces redacted values
+    TRUNCATION_MARKER = "...[truncated]"  # Appended to lines cut by max_line_bytes
+    LATENCY_BUCKETS_US = (1, 2, 5, 10, 20, 50, 100, 200, 500, 1000, 2000, 5000, 10000, 20000, 50000, 100000)
+    THROTTLE_CACHE_SIZE = 1024  # Maximum number of messages remembered by the throttled log methods
 
-    def __init__(self, show_time=True, color_enabled=None):
+    def __init__(self, show_time=True, color_enabled=None, show_time_relative=False, console_line_buffered=False):
         """
         Initialize a Logly instance.
 
@@ -83,16 +291,68 @@ class Logly:
         - logged_messages (list): List to store logged messages.
         - default_file_path (str): Default file path for logging.
         - default_max_file_size (int): Default maximum file size for logging.
+        - max_line_bytes (int): Maximum length in bytes of a line written to a log file, or None for no limit.
+        - record_separator (str): Separator written after each log message in files and streams.
         - show_time (bool): Flag indicating whether to include timestamps in log messages.
+        - show_time_relative (bool): Flag indicating whether the console shows the time since the previous log message
+                                     (e.g. "+1.234s") instead of the date and time.
+        - console_line_buffered (bool): Flag indicating whether the console is flushed after every log message,
+                                        so messages show up promptly when the output is piped.
+        - total_logs (int): Number of log messages processed.
+        - bytes_written (dict): Number of bytes written to each log file, keyed by file path.
+        - errors_count (int): Number of failed writes to log files and streams, and of stream conditions that raised.
+        - dropped_logs (int): Number of log messages that were suppressed instead of logged.
+        - level (str): Minimum level of the messages that are logged, or None to log every level.
+        - measure_latency (bool): Flag indicating whether the duration of each log call is measured.
+        - sample_rate (float): Fraction of the messages below sample_min_level that are logged, or None to log all.
+        - sample_min_level (str): Messages at or above this level are never dropped by sampling.
+        - debug_sample_rate (float): Fraction of the DEBUG messages logged even when they are below the minimum level,
+                                     or None to skip all of them.
+        - redact_keys (set): Keys whose values are replaced with REDACTED (compared case-insensitively).
+        - redact_pattern (re.Pattern): Pattern whose matches in keys and values are replaced with REDACTED, or None.
+        - filters (list): [callback, warned] pairs added with add_filter, in registration order.
+        - streams (list): [stream, when, warned] entries for the file-like objects added with add_stream.
+        - color_map (dict): Mapping of log levels to color codes for this instance, initialized from COLOR_MAP.
+        - level_aliases (dict): Mapping of alternative level names to the level they are logged as.
+        - level_severities (dict): Mapping of log levels to their severity for this instance,
+                                   initialized from LEVEL_SEVERITY.
         """
         self.logging_enabled = False
         self.log_to_file_enabled = True
         self.logged_messages = []
         self.default_file_path = None
         self.default_max_file_size = self.DEFAULT_MAX_FILE_SIZE_MB
+        self.max_line_bytes = None
+        self.record_separator = "\n"
         self.show_time = show_time
+        self.show_time_relative = show_time_relative
+        self.console_line_buffered = console_line_buffered
         self.color_enabled = color_enabled if color_enabled is not None else self.DEFAULT_COLOR_ENABLED  # Use the provided value or default
         self.default_color_enabled = self.color_enabled  # Store the default color state
+        self.total_logs = 0
+        self.bytes_written = {}
+        self.errors_count = 0
+        self.dropped_logs = 0
+        self.level = None
+        self.measure_latency = False
+        self._latency_counts = [0] * (len(self.LATENCY_BUCKETS_US) + 1)  # Last bucket counts durations above the largest bound
+        self._latency_max = 0.0
+        self.sample_rate = None
+        self.sample_min_level = "ERROR"
+        self.debug_sample_rate = None
+        self.redact_keys = set()
+        self.redact_pattern = None
+        self.filters = []
+        self.streams = []
+        self.color_map = dict(self.COLOR_MAP)
+        self.level_aliases = {}
+        self.level_severities = dict(self.LEVEL_SEVERITY)
+        self._test_clock = None
+        self._last_log_time = None
+        self._console = None  # Original sys.stdout while it is captured
+        self._throttle_times = OrderedDict()  # Message or throttle key -> monotonic time it was last logged
+        self._throttle_max_interval_ms = 0  # Largest interval seen, older entries are forgotten
+        self._once_messages = set()  # Messages already logged by log_once
 
     def start_logging(self):
         """
@@ -137,6 +397,433 @@ class Logly:
         """
         self.default_max_file_size = max_file_size
 
+    def set_max_line_bytes(self, max_line_bytes):
+        """
+        Set the maximum length of a line written to a log file. Longer lines are cut and end with TRUNCATION_MARKER.
+
+        Parameters:
+        - max_line_bytes (int): The maximum line length in bytes, or None for no limit.
+        """
+        if max_line_bytes is not None and (not isinstance(max_line_bytes, int) or max_line_bytes <= 0):
+            raise InvalidConfigError(f"max_line_bytes must be a positive integer: {max_line_bytes!r}")
+        self.max_line_bytes = max_line_bytes
+
+    def set_record_separator(self, separator):
+        """
+        Set the separator written after each log message in files and streams.
+
+        Parameters:
+        - separator (str): The separator, e.g. "\\0" so multi-line messages stay unambiguous. Defaults to "\\n".
+        """
+        if not isinstance(separator, str) or not separator:
+            raise InvalidConfigError(f"The record separator must be a non-empty string: {separator!r}")
+        self.record_separator = separator
+
+    def set_console_encoding(self, encoding="utf-8"):
+        """
+        Set the encoding used for console output, so non-ASCII messages render correctly on consoles
+        with a legacy code page. On Windows, the console is also switched to the UTF-8 code page.
+
+        Parameters:
+        - encoding (str): The encoding name. Defaults to "utf-8".
+        """
+        try:
+            encoding = codecs.lookup(encoding).name
+        except (LookupError, TypeError):
+            raise InvalidConfigError(f"Unknown console encoding: {encoding!r}")
+
+        if os.name == "nt" and encoding == "utf-8":
+            import ctypes
+            ctypes.windll.kernel32.SetConsoleOutputCP(65001)
+
+        console = self._console or sys.stdout
+        if callable(getattr(console, "reconfigure", None)):
+            console.reconfigure(encoding=encoding)
+
+    def register_level(self, name, severity="INFO", color=None):
+        """
+        Register a custom log level, which can then be logged with log_function (e.g. logly.log_function("NOTICE", "msg")).
+
+        Parameters:
+        - name (str): The name of the level, shown in the log messages.
+        - severity (str or int, optional): The existing level it filters and samples like, or a numeric severity
+                                           (see LEVEL_SEVERITY). Defaults to "INFO".
+        - color (str, optional): A color name from COLOR or one of the COLOR constants. Defaults to the color of
+                                 the severity level, if it has one.
+        """
+        if not isinstance(name, str) or not name.strip():
+            raise InvalidLogLevelError(f"Invalid log level: {name!r}")
+        name = name.strip().upper()
+
+        if isinstance(severity, int) and not isinstance(severity, bool):
+            severity_value = severity
+        else:
+            severity_value = self.level_severity(severity)
+            if severity_value is None:
+                raise InvalidLogLevelError(f"Invalid log level: {severity!r}")
+
+        self.level_severities[name] = severity_value
+        if color is not None:
+            self.color_map[name] = self._resolve_color(color)
+        elif isinstance(severity, str) and severity.upper() in self.color_map:
+            self.color_map[name] = self.color_map[severity.upper()]
+
+    def set_redaction(self, redact_keys=None, redact_pattern=None):
+        """
+        Mask sensitive keys and values in log messages. Applies to the console, files and streams alike.
+
+        Parameters:
+        - redact_keys (list, optional): Keys whose values are replaced with REDACTED, e.g. ["password", "token"].
+        - redact_pattern (str, optional): Regular expression whose matches in keys and values are replaced with
+                                          REDACTED, e.g. r"\\d{16}" for card numbers.
+        """
+        try:
+            pattern = re.compile(redact_pattern) if redact_pattern is not None else None
+        except (re.error, TypeError) as e:
+            raise InvalidConfigError(f"Invalid redact_pattern {redact_pattern!r}: {e}")
+        self.redact_keys = {str(key).lower() for key in redact_keys or ()}
+        self.redact_pattern = pattern
+
+    def _redact(self, key, value):
+        """
+        Mask the sensitive parts of a key and value as configured with set_redaction.
+
+        Parameters:
+        - key (str): The key associated with the log message.
+        - value (str): The value of the log message.
+
+        Returns:
+        - tuple: The key and value to log.
+        """
+        if key is not None and str(key).lower() in self.redact_keys:
+            value = self.REDACTED
+        if self.redact_pattern is not None:
+            if key is not None:
+                key = self.redact_pattern.sub(self.REDACTED, str(key))
+            value = self.redact_pattern.sub(self.REDACTED, str(value))
+        return key, value
+
+    def set_level(self, level):
+        """
+        Set the minimum level of the messages that are logged. Messages with a lower severity are skipped,
+        messages with a level that has no severity are always logged.
+
+        Parameters:
+        - level (str): Log level (e.g., "DEBUG", "WARNING"), or None to log every level.
+                       Level aliases are resolved before the level is stored.
+        """
+        if level is not None:
+            if self.level_severity(level) is None:
+                raise InvalidLogLevelError(f"Invalid log level: {level!r}")
+            level = self.level_aliases.get(level.upper(), level.upper())
+        self.level = level
+
+    def get_level(self):
+        """
+        Get the minimum level of the messages that are logged.
+
+        Returns:
+        - str: The minimum log level, or None if every level is logged.
+        """
+        return self.level
+
+    def is_enabled(self, level):
+        """
+        Check whether messages of a level pass the minimum level set with set_level, e.g. to skip building
+        expensive log messages. Sampling and filters are not taken into account.
+
+        Parameters:
+        - level (str): Log level (e.g., "DEBUG", "INFO"). Level aliases are resolved first.
+
+        Returns:
+        - bool: True if messages of the level are logged, False if they are skipped.
+        """
+        if self.level is None:
+            return True
+        severity = self.level_severity(level)
+        return severity is None or severity >= self.level_severity(self.level)
+
+    def set_sample_rate(self, sample_rate, sample_min_level="ERROR"):
+        """
+        Log only a fraction of the messages below a given level, e.g. to cut down high-volume DEBUG logging.
+
+        Parameters:
+        - sample_rate (float): Fraction of the messages to log, between 0.0 and 1.0. None or 1.0 logs everything.
+        - sample_min_level (str, optional): Messages at or above this level are always logged. Defaults to "ERROR".
+        """
+        if sample_rate is not None and (not isinstance(sample_rate, (int, float)) or not 0.0 <= sample_rate <= 1.0):
+            raise InvalidConfigError(f"sample_rate must be between 0.0 and 1.0: {sample_rate!r}")
+        if self.level_severity(sample_min_level) is None:
+            raise InvalidLogLevelError(f"Invalid log level: {sample_min_level!r}")
+        self.sample_rate = sample_rate
+        self.sample_min_level = sample_min_level.upper()
+
+    def set_debug_sample_rate(self, debug_sample_rate):
+        """
+        Log a fraction of the DEBUG messages even when the minimum level set with set_level skips them,
+        e.g. to keep some debug breadcrumbs in production.
+
+        Parameters:
+        - debug_sample_rate (float): Fraction of the DEBUG messages to log, between 0.0 and 1.0. None or 0.0 logs none.
+        """
+        if debug_sample_rate is not None and (not isinstance(debug_sample_rate, (int, float))
+                                              or not 0.0 <= debug_sample_rate <= 1.0):
+            raise InvalidConfigError(f"debug_sample_rate must be between 0.0 and 1.0: {debug_sample_rate!r}")
+        self.debug_sample_rate = debug_sample_rate
+
+    def set_level_aliases(self, aliases):
+        """
+        Set alternative names for log levels.
+
+        Parameters:
+        - aliases (dict): Mapping of alias to level, e.g. {"FATAL": "CRITICAL", "NOTICE": "INFO"}.
+        """
+        if not isinstance(aliases, dict) or not all(isinstance(k, str) and isinstance(v, str)
+                                                    for k, v in aliases.items()):
+            raise InvalidConfigError(f"Level aliases must be a dict of level names: {aliases!r}")
+        self.level_aliases = {alias.upper(): level.upper() for alias, level in aliases.items()}
+
+    def add_stream(self, stream, when=None):
+        """
+        Add a file-like object that receives every log message.
+
+        Parameters:
+        - stream (object): Any object with a write method (and optionally a flush method), e.g. io.StringIO.
+        - when (callable, optional): Called with a dict of the record (level, key, value) for each message.
+                                     The message is only written to the stream when it returns True.
+                                     If it raises an exception, the message is not written to this stream
+                                     and the logger warns once. Defaults to None.
+        """
+        if not callable(getattr(stream, "write", None)):
+            raise InvalidConfigError(f"The stream does not have a write method: {stream!r}")
+        if when is not None and not callable(when):
+            raise InvalidConfigError(f"The when argument must be callable: {when!r}")
+        self.streams.append([stream, when, False])  # The flag is set once the when callback warned
+
+    def add_filter(self, log_filter):
+        """
+        Add a callback that can drop or change log messages. Filters run in registration order, before
+        the message is formatted, and are called with a dict of the record (level, key, value).
+        A filter returning False drops the message, a filter returning a dict replaces the record fields
+        it contains, and any other return value keeps the record as it is. A filter that raises an exception
+        keeps the record and warns once.
+
+        Parameters:
+        - log_filter (callable): The filter.
+        """
+        if not callable(log_filter):
+            raise InvalidConfigError(f"The filter must be callable: {log_filter!r}")
+        self.filters.append([log_filter, False])  # The flag is set once the filter warned
+
+    def remove_filter(self, log_filter):
+        """
+        Remove a callback added with add_filter.
+
+        Parameters:
+        - log_filter (callable): The filter to remove.
+
+        Returns:
+        - bool: True if the filter was removed, False if it was not added.
+        """
+        for entry in self.filters:
+            if entry[0] == log_filter:
+                self.filters.remove(entry)
+                return True
+        return False
+
+    def _apply_filters(self, level, key, value):
+        """
+        Run the filters added with add_filter on a record.
+
+        Parameters:
+        - level (str): Log level of the message.
+        - key (str): The key associated with the log message.
+        - value (str): The value of the log message.
+
+        Returns:
+        - dict: The record (level, key, value) to log, or None if a filter dropped it.
+        """
+        record = {"level": level, "key": key, "value": value}
+        for entry in list(self.filters):
+            log_filter, warned = entry
+            try:
+                result = log_filter(dict(record))
+            except Exception as e:
+                if not warned:
+                    entry[1] = True
+                    warnings.warn(f"Logly filter {log_filter!r} raised an exception and was ignored: {e!r}",
+                                  RuntimeWarning)
+                continue
+            if result is False:
+                return None
+            if isinstance(result, dict):
+                record.update((name, result[name]) for name in ("level", "key", "value") if name in result)
+        return record
+
+    def add_memory_buffer(self, capacity=1000, when=None):
+        """
+        Keep the most recent log messages in memory, e.g. to dump them when an error occurs.
+
+        Parameters:
+        - capacity (int, optional): Maximum number of log messages kept. Defaults to 1000.
+        - when (callable, optional): Same as for add_stream. Defaults to None.
+
+        Returns:
+        - MemoryBuffer: The buffer; call its dump method to get the messages. Remove it with remove_stream.
+        """
+        if not isinstance(capacity, int) or capacity <= 0:
+            raise InvalidConfigError(f"capacity must be a positive integer: {capacity!r}")
+        buffer = MemoryBuffer(capacity, self)
+        self.add_stream(buffer, when)
+        return buffer
+
+    def remove_stream(self, stream):
+        """
+        Remove a file-like object added with add_stream.
+
+        Parameters:
+        - stream (object): The stream to remove.
+
+        Returns:
+        - bool: True if the stream was removed, False if it was not added.
+        """
+        for entry in self.streams:
+            if entry[0] is stream:
+                self.streams.remove(entry)
+                return True
+        return False
+
+    def capture_stdout(self, level="INFO"):
+        """
+        Redirect sys.stdout writes into the logger, one log message per line.
+
+        Parameters:
+        - level (str): Log level used for the captured lines. Defaults to "INFO".
+        """
+        if isinstance(sys.stdout, _CaptureStream):
+            return
+        self._console = sys.stdout
+        sys.stdout = _CaptureStream(self, level, sys.stdout)
+
+    def release_stdout(self):
+        """
+        Restore sys.stdout after capture_stdout, logging any buffered partial line.
+        """
+        if isinstance(sys.stdout, _CaptureStream):
+            capture = sys.stdout
+            capture.release()
+            sys.stdout = capture.original
+            self._console = None
+
+    def capture_stderr(self, level="ERROR"):
+        """
+        Redirect sys.stderr writes into the logger, one log message per line.
+
+        Parameters:
+        - level (str): Log level used for the captured lines. Defaults to "ERROR".
+        """
+        if isinstance(sys.stderr, _CaptureStream):
+            return
+        sys.stderr = _CaptureStream(self, level, sys.stderr)
+
+    def release_stderr(self):
+        """
+        Restore sys.stderr after capture_stderr, logging any buffered partial line.
+        """
+        if isinstance(sys.stderr, _CaptureStream):
+            capture = sys.stderr
+            capture.release()
+            sys.stderr = capture.original
+
+    def level_colors(self):
+        """
+        Get the current mapping of log levels to color codes.
+
+        Returns:
+        - dict: A copy of the level to color code mapping.
+        """
+        return dict(self.color_map)
+
+    def set_level_color(self, level, color):
+        """
+        Set the color used for a single log level.
+
+        Parameters:
+        - level (str): Log level (e.g., "INFO", "ERROR"). Level aliases are resolved first.
+        - color (str): A color name from COLOR (e.g., "RED") or one of the COLOR constants (e.g., logly.COLOR.RED).
+        """
+        if self.level_severity(level) is None and not (isinstance(level, str) and level.upper() in self.color_map):
+            raise InvalidLogLevelError(f"Invalid log level: {level!r}")
+        level = self.level_aliases.get(level.upper(), level.upper())
+        self.color_map[level] = self._resolve_color(color)
+
+    def _resolve_color(self, color):
+        """
+        Resolve a color name or constant to its color code.
+
+        Parameters:
+        - color (str): A color name from COLOR (e.g., "RED") or one of the COLOR constants (e.g., logly.COLOR.RED).
+
+        Returns:
+        - str: The color code.
+        """
+        colors = {name: code for name, code in vars(self.COLOR).items() if not name.startswith("_")}
+        if isinstance(color, str) and color.upper() in colors:
+            return colors[color.upper()]
+        if color not in colors.values():
+            raise InvalidConfigError(f"Invalid color: {color!r}. Valid colors are: {', '.join(colors)}")
+        return color
+
+    def level_severity(self, level):
+        """
+        Get the severity of a log level.
+
+        Parameters:
+        - level (str): Log level (e.g., "INFO", "ERROR"). Level aliases are resolved first.
+
+        Returns:
+        - int: The severity of the level, or None if the level is unknown.
+        """
+        if not isinstance(level, str):
+            return None
+        level = level.upper()
+        return self.level_severities.get(self.level_aliases.get(level, level))
+
+    def _is_sampled_out(self, level):
+        """
+        Decide whether a message is dropped by sampling.
+
+        Parameters:
+        - level (str): Log level of the message.
+
+        Returns:
+        - bool: True if the message should be dropped.
+        """
+        if self.sample_rate is None or self.sample_rate >= 1.0:
+            return False
+        severity = self.level_severity(level)
+        if severity is not None and severity >= self.level_severity(self.sample_min_level):
+            return False
+        return random.random() >= self.sample_rate
+
+    def _set_test_clock(self, timestamp):
+        """
+        Pin the time used for log timestamps and relative times. Intended for tests only.
+
+        Parameters:
+        - timestamp (datetime): The time to use for every log message, or None to use the real clock again.
+        """
+        self._test_clock = timestamp
+
+    def _now(self):
+        """
+        Get the current time, honouring a clock pinned with _set_test_clock.
+
+        Returns:
+        - datetime: The current time.
+        """
+        return self._test_clock if self._test_clock is not None else datetime.now()
+
     def get_current_datetime(self):
         """
         Get the current date and time as a formatted string.
@@ -144,7 +831,7 @@ class Logly:
         Returns:
         - str: Formatted date and time string.
         """
-        return datetime.now().strftime("%Y-%m-%d %H:%M:%S")
+        return self._now().strftime("%Y-%m-%d %H:%M:%S")
 
     def remove_color_codes(self, text):
         """
@@ -158,8 +845,156 @@ class Logly:
         """
         return re.sub(r'\x1B(?:[@-Z\\-_]|\[[0-?]*[ -/]*[@-~])', '', text)
 
+    def truncate_line(self, text, max_bytes):
+        """
+        Cut a line to a maximum number of bytes without splitting a UTF-8 character.
+
+        Parameters:
+        - text (str): The line to cut.
+        - max_bytes (int): The maximum length of the result in bytes, including TRUNCATION_MARKER.
+
+        Returns:
+        - str: The line, cut and ending with TRUNCATION_MARKER if it was longer than max_bytes.
+        """
+        data = text.encode()
+        if len(data) <= max_bytes:
+            return text
+        marker = self.TRUNCATION_MARKER.encode()
+        keep = max(max_bytes - len(marker), 0)
+        return (data[:keep].decode(errors="ignore") + self.TRUNCATION_MARKER).encode()[:max_bytes].decode(errors="ignore")
+
+    def total_bytes_written(self):
+        """
+        Get the total number of bytes written to log files.
+
+        Returns:
+        - int: Sum of the bytes written to every log file by this instance.
+        """
+        return sum(self.bytes_written.values())
+
+    def metrics(self):
+        """
+        Get the metric counters as a dict.
+
+        Returns:
+        - dict: total_logs, bytes_written (total over all log files), errors_count and dropped_logs.
+        """
+        return {
+            "total_logs": self.total_logs,
+            "bytes_written": self.total_bytes_written(),
+            "errors_count": self.errors_count,
+            "dropped_logs": self.dropped_logs,
+        }
+
+    def reset_metrics(self):
+        """
+        Reset all metric counters to zero.
+        """
+        self.total_logs = 0
+        self.bytes_written = {}
+        self.errors_count = 0
+        self.dropped_logs = 0
+        self._latency_counts = [0] * (len(self.LATENCY_BUCKETS_US) + 1)
+        self._latency_max = 0.0
+
+    def set_measure_latency(self, measure_latency):
+        """
+        Enable or disable measuring how long each log call takes (formatting and writing).
+
+        Parameters:
+        - measure_latency (bool): Whether to measure the log call durations.
+        """
+        self.measure_latency = bool(measure_latency)
+
+    def _record_latency(self, duration_us):
+        """
+        Add a log call duration to the latency histogram.
+
+        Parameters:
+        - duration_us (float): The duration in microseconds.
+        """
+        for index, bound in enumerate(self.LATENCY_BUCKETS_US):
+            if duration_us <= bound:
+                break
+        else:
+            index = len(self.LATENCY_BUCKETS_US)
+        self._latency_counts[index] += 1
+        self._latency_max = max(self._latency_max, duration_us)
+
+    def latency_stats(self):
+        """
+        Get percentiles of the measured log call durations.
+
+        The durations are kept in the fixed buckets of LATENCY_BUCKETS_US, so each percentile is the upper bound
+        of the bucket it falls in (or the largest measured duration above the last bucket).
+
+        Returns:
+        - dict: count, and p50, p95 and p99 in microseconds (None when nothing was measured).
+        """
+        count = sum(self._latency_counts)
+        stats = {"count": count}
+        for name, percentile in (("p50", 0.50), ("p95", 0.95), ("p99", 0.99)):
+            stats[name] = None
+            if count:
+                cumulative = 0
+                for index, bucket_count in enumerate(self._latency_counts):
+                    cumulative += bucket_count
+                    if cumulative >= percentile * count:
+                        bounds = self.LATENCY_BUCKETS_US
+                        stats[name] = bounds[index] if index < len(bounds) else self._latency_max
+                        break
+        return stats
+
+    def _relative_time(self):
+        """
+        Get the time elapsed since the previous call as a formatted string, and remember the current time.
+        Uses the monotonic clock, so changes to the system time do not affect it.
+
+        Returns:
+        - str: Elapsed time such as "+1.234s" ("+0.000s" on the first call).
+        """
+        now = self._test_clock.timestamp() if self._test_clock is not None else time.monotonic()
+        elapsed = 0.0 if self._last_log_time is None else max(now - self._last_log_time, 0.0)
+        self._last_log_time = now
+        return f"+{elapsed:.3f}s"
+
+    def _format_message(self, level, key, value, color, show_time, timestamp, color_enabled, raw=False):
+        """
+        Build the text of a log message.
+
+        Parameters:
+        - level (str): Log level (e.g., "INFO", "ERROR").
+        - key (str): The key associated with the log message.
+        - value (str): The value of the log message.
+        - color (str): ANSI color code for the log message.
+        - show_time (bool): Whether to include the timestamp in the log message.
+        - timestamp (str): The timestamp to include.
+        - color_enabled (bool): Whether to enable color in the log message.
+        - raw (bool, optional): Whether to return only the key and value, without prefix or color. Defaults to False.
+
+        Returns:
+        - str: The formatted log message.
+        """
+        if raw:
+            # Write the message as it is, the key is left out when there is none
+            return f"{value}" if key is None else f"{key}: {value}"
+        elif color_enabled and show_time:
+            # Apply color if both color and time are enabled
+            color = color or self.color_map.get(level, self.COLOR.BLUE)
+            return f"[{timestamp}] {level}: {color}{key}: {value}{Style.RESET_ALL}"
+        elif color_enabled and not show_time:
+            # Apply color if only color is enabled
+            color = color or self.color_map.get(level, self.COLOR.BLUE)
+            return f" {level}: {color}{key}: {value}{Style.RESET_ALL}"
+        elif not color_enabled and show_time:
+            # Do not apply color, but include timestamp if only time is enabled
+            return f"[{timestamp}] {level}: {key}: {value}"
+        else:
+            # Do not apply color or timestamp if neither is enabled
+            return f"{level}: {key}: {value}"
+
     def _log(self, level, key, value, color=None, log_to_file=True, file_path=None, file_name=None, max_file_size=None,
-             auto=True, show_time=None, color_enabled=None):
+             auto=True, show_time=None, color_enabled=None, raw=False):
         """
         Internal method to log a message.
 
@@ -175,36 +1010,76 @@ class Logly:
         - auto (bool): Whether to auto-delete log file data when the size limit is reached.
         - show_time (bool): Whether to include timestamps in the log message.
         - color_enabled (bool): Whether to enable color in the log message.
+        - raw (bool): Whether to write the message without the level and time prefix and without color.
 
         """
+        if not self.is_enabled(level):
+            # A fraction of the DEBUG messages can still be logged as breadcrumbs
+            if not (self.debug_sample_rate and self.level_severity(level) == self.level_severities["DEBUG"]
+                    and random.random() < self.debug_sample_rate):
+                return
+
+        if self._is_sampled_out(level):
+            self.dropped_logs += 1
+            return
+
+        if self.filters:
+            record = self._apply_filters(level, key, value)
+            if record is None:
+                self.dropped_logs += 1
+                return
+            level, key, value = record["level"], record["key"], record["value"]
+
+        if self.redact_keys or self.redact_pattern is not None:
+            key, value = self._redact(key, value)
+
+        self.total_logs += 1
+
         color_enabled = color_enabled if color_enabled is not None else self.color_enabled  # Use the provided value or default
         if show_time is None:
             show_time = self.show_time
 
         timestamp = "" if not show_time else self.get_current_datetime()
-
-        if color_enabled and show_time:
-            # Apply color if both color and time are enabled
-            color = color or self.COLOR_MAP.get(level, self.COLOR.BLUE)
-            log_message = f"[{timestamp}] {level}: {color}{key}: {value}{Style.RESET_ALL}"
-        elif color_enabled and not show_time:
-            # Apply color if only color is enabled
-            color = color or self.COLOR_MAP.get(level, self.COLOR.BLUE)
-            log_message = f" {level}: {color}{key}: {value}{Style.RESET_ALL}"
-        elif not color_enabled and show_time:
-            # Do not apply color, but include timestamp if only time is enabled
-            log_message = f"[{timestamp}] {level}: {key}: {value}"
-        else:
-            # Do not apply color or timestamp if neither is enabled
-            log_message = f"{level}: {key}: {value}"
-
-        # Log to console
-        print(log_message)
+        log_message = self._format_message(level, key, value, color, show_time, timestamp, color_enabled, raw)
+
+        console_message = log_message
+        if show_time and self.show_time_relative and not raw:
+            # Show the time since the previous log message on the console only
+            console_message = self._format_message(level, key, value, color, show_time, self._relative_time(),
+                                                   color_enabled)
+
+        # Log to console (bypassing sys.stdout while it is captured)
+        print(console_message, file=self._console, flush=self.console_line_buffered)
+
+        # Log to streams, a failing stream must not keep the message from the other streams and the file
+        stream_errors = []
+        for entry in self.streams:
+            stream, when, warned = entry
+            if when is not None:
+                try:
+                    if not when({"level": level, "key": key, "value": value}):
+                        continue
+                except Exception as e:
+                    self.errors_count += 1
+                    if not warned:
+                        entry[2] = True
+                        warnings.warn(f"Logly stream condition {when!r} raised an exception and the stream was "
+                                      f"skipped: {e!r}", RuntimeWarning)
+                    continue
+            try:
+                stream.write(self.remove_color_codes(log_message) + self.record_separator)
+                if callable(getattr(stream, "flush", None)):
+                    stream.flush()
+            except Exception as e:
+                self.errors_count += 1
+                stream_errors.append(f"{stream!r}: {e}")
 
         if self.log_to_file_enabled and log_to_file:
             try:
                 # Remove color codes before storing in the log file
                 log_message_without_color = self.remove_color_codes(log_message)
+                if self.max_line_bytes:
+                    log_message_without_color = self.truncate_line(log_message_without_color, self.max_line_bytes)
 
                 # Determine the file path and name
                 if file_path is None:
@@ -246,18 +1121,28 @@ class Logly:
                         file_path = f"{file_base}_{count}{file_ext}"
 
                 # Open the file in append mode, creating it if it doesn't exist
-                with open(file_path, "a" if file_exists else "w") as log_file:
-                    log_file.write(log_message_without_color + "\n")
+                record = log_message_without_color + self.record_separator
+                with open(file_path, "a" if file_exists else "w", encoding="utf-8") as log_file:
+                    log_file.write(record)
+
+                # Count the bytes as they end up on disk, after newline translation
+                line_bytes = len(record.replace("\n", os.linesep).encode("utf-8"))
+                self.bytes_written[file_path] = self.bytes_written.get(file_path, 0) + line_bytes
 
                 self.logged_messages.append(log_message + "\n")
 
             except (FileNotFoundError, PermissionError) as e:
+                self.errors_count += 1
                 raise FileAccessError(f"Error accessing the log file: {e}")
             except Exception as e:
+                self.errors_count += 1
                 raise FileCreationError(f"Error creating or writing to the log file: {e}")
 
+        if stream_errors:
+            raise StreamWriteError(f"Error writing to the log stream: {'; '.join(stream_errors)}")
+
     def log_function(self, level, key_or_value, value=None, color=None, log_to_file=True, file_path=None,
-                     file_name=None, max_file_size=None, auto=True, show_time=None,color_enabled=None):
+                     file_name=None, max_file_size=None, auto=True, show_time=None,color_enabled=None, raw=False):
         """
         Log a message with exception handling.
 
@@ -273,7 +1158,11 @@ class Logly:
         - max_file_size (int, optional): Maximum file size for logging. Defaults to None.
         - auto (bool, optional): Whether to auto-delete log file data when the size limit is reached. Defaults to True.
         - show_time (bool, optional): Whether to include timestamps in the log message. Defaults to None.
+        - raw (bool, optional): Whether to leave out the level and time prefix. Defaults to False.
         """
+        level = level.upper()
+        level = self.level_aliases.get(level, level)
+
         if value is None:
             # If only one parameter is provided, consider it as the value, and set key to None
             key = None
@@ -282,10 +1171,16 @@ class Logly:
             # If two parameters are provided, consider the first as the key and the second as the value
             key = key_or_value
 
-        self._log(level, key, value, color, log_to_file, file_path, file_name, max_file_size, auto, show_time,color_enabled)
+        if not self.measure_latency:
+            self._log(level, key, value, color, log_to_file, file_path, file_name, max_file_size, auto, show_time,color_enabled, raw)
+            return
+
+        start = time.perf_counter()
+        self._log(level, key, value, color, log_to_file, file_path, file_name, max_file_size, auto, show_time,color_enabled, raw)
+        self._record_latency((time.perf_counter() - start) * 1000000)
 
     def info(self, key_or_value, value=None, color=None, log_to_file=True, file_path=None, file_name=None,
-             max_file_size=None, auto=True, show_time=None,color_enabled=None):
+             max_file_size=None, auto=True, show_time=None,color_enabled=None, raw=False):
         """
         Log a message with the INFO level.
 
@@ -300,12 +1195,13 @@ class Logly:
         - max_file_size (int, optional): Maximum file size for logging. Defaults to None.
         - auto (bool, optional): Whether to auto-delete log file data when the size limit is reached. Defaults to True.
         - show_time (bool, optional): Whether to include timestamps in the log message. Defaults to None.
+        - raw (bool, optional): Whether to leave out the level and time prefix. Defaults to False.
         """
         self.log_function("INFO", key_or_value, value, color, log_to_file, file_path, file_name, max_file_size, auto,
-                          show_time,color_enabled)
+                          show_time,color_enabled, raw)
 
     def warn(self, key_or_value, value=None, color=None, log_to_file=True, file_path=None, file_name=None,
-             max_file_size=None, auto=True, show_time=None,color_enabled=None):
+             max_file_size=None, auto=True, show_time=None,color_enabled=None, raw=False):
         """
         Log a message with the WARNING level.
 
@@ -320,12 +1216,13 @@ class Logly:
         - max_file_size (int, optional): Maximum file size for logging. Defaults to None.
         - auto (bool, optional): Whether to auto-delete log file data when the size limit is reached. Defaults to True.
         - show_time (bool, optional): Whether to include timestamps in the log message. Defaults to None.
+        - raw (bool, optional): Whether to leave out the level and time prefix. Defaults to False.
         """
         self.log_function("WARNING", key_or_value, value, color, log_to_file, file_path, file_name, max_file_size, auto,
-                          show_time,color_enabled)
+                          show_time,color_enabled, raw)
 
     def error(self, key_or_value, value=None, color=None, log_to_file=True, file_path=None, file_name=None,
-              max_file_size=None, auto=True, show_time=None,color_enabled=None):
+              max_file_size=None, auto=True, show_time=None,color_enabled=None, raw=False):
         """
         Log a message with the ERROR level.
 
@@ -340,12 +1237,13 @@ class Logly:
         - max_file_size (int, optional): Maximum file size for logging. Defaults to None.
         - auto (bool, optional): Whether to auto-delete log file data when the size limit is reached. Defaults to True.
         - show_time (bool, optional): Whether to include timestamps in the log message. Defaults to None.
+        - raw (bool, optional): Whether to leave out the level and time prefix. Defaults to False.
         """
         self.log_function("ERROR", key_or_value, value, color, log_to_file, file_path, file_name, max_file_size, auto,
-                          show_time,color_enabled)
+                          show_time,color_enabled, raw)
 
     def debug(self, key_or_value, value=None, color=None, log_to_file=True, file_path=None, file_name=None,
-              max_file_size=None, auto=True, show_time=None,color_enabled=None):
+              max_file_size=None, auto=True, show_time=None,color_enabled=None, raw=False):
         """
         Log a message with the DEBUG level.
 
@@ -360,12 +1258,13 @@ class Logly:
         - max_file_size (int, optional): Maximum file size for logging. Defaults to None.
         - auto (bool, optional): Whether to auto-delete log file data when the size limit is reached. Defaults to True.
         - show_time (bool, optional): Whether to include timestamps in the log message. Defaults to None.
+        - raw (bool, optional): Whether to leave out the level and time prefix. Defaults to False.
         """
         self.log_function("DEBUG", key_or_value, value, color, log_to_file, file_path, file_name, max_file_size, auto,
-                          show_time,color_enabled)
+                          show_time,color_enabled, raw)
 
     def critical(self, key_or_value, value=None, color=None, log_to_file=True, file_path=None, file_name=None,
-                 max_file_size=None, auto=True, show_time=None,color_enabled=None):
+                 max_file_size=None, auto=True, show_time=None,color_enabled=None, raw=False):
         """
         Log a critical message.
 
@@ -380,13 +1279,14 @@ class Logly:
         - max_file_size (int, optional): Maximum file size for logging. Defaults to None.
         - auto (bool, optional): Whether to auto-delete log file data when the size limit is reached. Defaults to True.
         - show_time (bool, optional): Whether to include timestamps in the log message. Defaults to None.
+        - raw (bool, optional): Whether to leave out the level and time prefix. Defaults to False.
         """
         self.log_function("CRITICAL", key_or_value, value, color, log_to_file, file_path, file_name, max_file_size,
                           auto,
-                          show_time,color_enabled)
+                          show_time,color_enabled, raw)
 
     def fatal(self, key_or_value, value=None, color=None, log_to_file=True, file_path=None, file_name=None,
-              max_file_size=None, auto=True, show_time=None,color_enabled=None):
+              max_file_size=None, auto=True, show_time=None,color_enabled=None, raw=False):
         """
         Log a fatal message.
 
@@ -401,12 +1301,13 @@ class Logly:
         - max_file_size (int, optional): Maximum file size for logging. Defaults to None.
         - auto (bool, optional): Whether to auto-delete log file data when the size limit is reached. Defaults to True.
         - show_time (bool, optional): Whether to include timestamps in the log message. Defaults to None.
+        - raw (bool, optional): Whether to leave out the level and time prefix. Defaults to False.
         """
         self.log_function("FATAL", key_or_value, value, color, log_to_file, file_path, file_name, max_file_size, auto,
-                          show_time,color_enabled)
+                          show_time,color_enabled, raw)
 
     def trace(self, key_or_value, value=None, color=None, log_to_file=True, file_path=None, file_name=None,
-              max_file_size=None, auto=True, show_time=None,color_enabled=None):
+              max_file_size=None, auto=True, show_time=None,color_enabled=None, raw=False):
         """
         Log a trace message.
 
@@ -421,12 +1322,13 @@ class Logly:
         - max_file_size (int, optional): Maximum file size for logging. Defaults to None.
         - auto (bool, optional): Whether to auto-delete log file data when the size limit is reached. Defaults to True.
         - show_time (bool, optional): Whether to include timestamps in the log message. Defaults to None.
+        - raw (bool, optional): Whether to leave out the level and time prefix. Defaults to False.
         """
         self.log_function("TRACE", key_or_value, value, color, log_to_file, file_path, file_name, max_file_size, auto,
-                          show_time,color_enabled)
+                          show_time,color_enabled, raw)
 
     def log(self, key_or_value, value=None, color=None, log_to_file=True, file_path=None, file_name=None,
-            max_file_size=None, auto=True, show_time=None,color_enabled=None):
+            max_file_size=None, auto=True, show_time=None,color_enabled=None, raw=False):
         """
         Log an info message.
 
@@ -441,6 +1343,182 @@ class Logly:
         - max_file_size (int, optional): Maximum file size for logging. Defaults to None.
         - auto (bool, optional): Whether to auto-delete log file data when the size limit is reached. Defaults to True.
         - show_time (bool, optional): Whether to include timestamps in the log message. Defaults to None.
+        - raw (bool, optional): Whether to leave out the level and time prefix. Defaults to False.
         """
         self.log_function("LOG", key_or_value, value, color, log_to_file, file_path, file_name, max_file_size, auto,
-                          show_time,color_enabled)
+                          show_time,color_enabled, raw)
+
+    def log_throttled(self, level, key_or_value, value=None, interval_ms=60000, throttle_key=None, **kwargs):
+        """
+        Log a message at most once every interval_ms milliseconds, keyed by the message text or by throttle_key.
+
+        Parameters:
+        - level (str): Log level (e.g., "INFO", "ERROR").
+        - key_or_value (str): If a second parameter (value) is provided, this is considered as the key.
+                             If no second parameter is provided, this is considered as the value, and the key is set to None.
+        - value (str, optional): The value of the log message. Defaults to None.
+        - interval_ms (int, optional): Minimum time between two messages with the same throttle key in milliseconds.
+                                       Defaults to 60000.
+        - throttle_key (str, optional): Key shared by the messages that are throttled together, even if their text
+                                        differs. Defaults to None, which throttles by level and message text.
+        - **kwargs: Any other option accepted by log_function (color, log_to_file, file_path, ...).
+
+        Returns:
+        - bool: True if the message was logged, False if it was suppressed.
+        """
+        level = self.level_aliases.get(level.upper(), level.upper())  # Same level, same key, however it is spelled
+        message = f"{level}:{key_or_value}:{value}" if throttle_key is None else f"key:{throttle_key}"
+        now = time.monotonic()
+        last = self._throttle_times.get(message)
+        if last is not None and (now - last) * 1000 < interval_ms:
+            self.dropped_logs += 1
+            return False
+
+        self._throttle_times[message] = now
+        self._throttle_times.move_to_end(message)
+        self._throttle_max_interval_ms = max(self._throttle_max_interval_ms, interval_ms)
+        # Forget entries that can no longer suppress anything, and cap the size of the cache
+        while self._throttle_times:
+            oldest = next(iter(self._throttle_times.values()))
+            if len(self._throttle_times) <= self.THROTTLE_CACHE_SIZE and \
+                    (now - oldest) * 1000 < self._throttle_max_interval_ms:
+                break
+            self._throttle_times.popitem(last=False)
+
+        self.log_function(level, key_or_value, value, **kwargs)
+        return True
+
+    def info_throttled(self, key_or_value, value=None, interval_ms=60000, throttle_key=None, **kwargs):
+        """
+        Log a message with the INFO level at most once every interval_ms milliseconds.
+
+        See log_throttled for the parameters.
+        """
+        return self.log_throttled("INFO", key_or_value, value, interval_ms, throttle_key, **kwargs)
+
+    def warn_throttled(self, key_or_value, value=None, interval_ms=60000, throttle_key=None, **kwargs):
+        """
+        Log a message with the WARNING level at most once every interval_ms milliseconds.
+
+        See log_throttled for the parameters.
+        """
+        return self.log_throttled("WARNING", key_or_value, value, interval_ms, throttle_key, **kwargs)
+
+    def log_once(self, level, key_or_value, value=None, **kwargs):
+        """
+        Log a message only the first time it is seen for the lifetime of this instance.
+
+        Parameters:
+        - level (str): Log level (e.g., "INFO", "ERROR").
+        - key_or_value (str): If a second parameter (value) is provided, this is considered as the key.
+                             If no second parameter is provided, this is considered as the value, and the key is set to None.
+        - value (str, optional): The value of the log message. Defaults to None.
+        - **kwargs: Any other option accepted by log_function (color, log_to_file, file_path, ...).
+
+        Returns:
+        - bool: True if the message was logged, False if it was logged before.
+        """
+        level = self.level_aliases.get(level.upper(), level.upper())  # Same level, same key, however it is spelled
+        message = f"{level}:{key_or_value}:{value}"
+        if message in self._once_messages:
+            return False
+        self._once_messages.add(message)
+        self.log_function(level, key_or_value, value, **kwargs)
+        return True
+
+    def warn_once(self, key_or_value, value=None, **kwargs):
+        """
+        Log a message with the WARNING level only the first time it is seen.
+
+        See log_once for the parameters.
+        """
+        return self.log_once("WARNING", key_or_value, value, **kwargs)
+
+    def log_batch(self, records, **kwargs):
+        """
+        Log several messages in one call. All levels are checked before anything is logged.
+
+        Parameters:
+        - records (iterable): Tuples of (level, key_or_value) or (level, key, value).
+        - **kwargs: Any other option accepted by log_function (color, log_to_file, file_path, ...), applied to
+                    every message.
+        """
+        records = list(records)  # Records are read twice, so generators must be consumed once up front
+        for record in records:
+            if not isinstance(record, (tuple, list)) or len(record) not in (2, 3):
+                raise InvalidConfigError(f"Invalid log record, expected (level, key_or_value[, value]): {record!r}")
+            if self.level_severity(record[0]) is None:
+                raise InvalidLogLevelError(f"Invalid log level: {record[0]!r}")
+
+        for level, key_or_value, *value in records:
+            self.log_function(level.upper(), key_or_value, value[0] if value else None, **kwargs)
+
+    def expect(self, condition, key_or_value, value=None, **kwargs):
+        """
+        Log a message with the ERROR level when a condition is false.
+
+        Parameters:
+        - condition (bool): The condition that is expected to be true.
+        - key_or_value (str): If a second parameter (value) is provided, this is considered as the key.
+                             If no second parameter is provided, this is considered as the value, and the key is set to None.
+        - value (str, optional): The value of the log message. Defaults to None.
+        - **kwargs: Any other option accepted by log_function (color, log_to_file, file_path, ...).
+
+        Returns:
+        - bool: The condition, so the call can be used inline (e.g. if not logly.expect(...)).
+        """
+        if not condition:
+            self.log_function("ERROR", key_or_value, value, **kwargs)
+        return bool(condition)
+
+    def exception(self, key_or_value, value=None, **kwargs):
+        """
+        Log a message with the ERROR level, followed by the traceback of the exception being handled
+        on indented lines. Call it from an except block.
+
+        Parameters:
+        - key_or_value (str): If a second parameter (value) is provided, this is considered as the key.
+                             If no second parameter is provided, this is considered as the value, and the key is set to None.
+        - value (str, optional): The value of the log message. Defaults to None.
+        - **kwargs: Any other option accepted by log_function (color, log_to_file, file_path, ...).
+        """
+        if sys.exc_info()[1] is None:
+            details = "    No exception was present"
+        else:
+            details = self.format_traceback(*sys.exc_info())
+
+        if value is None:
+            key_or_value = f"{key_or_value}\n{details}"
+        else:
+            value = f"{value}\n{details}"
+        self.log_function("ERROR", key_or_value, value, **kwargs)
+
+    def format_traceback(self, exc_type, exc, tb):
+        """
+        Format an exception and its traceback as indented lines.
+
+        Parameters:
+        - exc_type (type): The exception class.
+        - exc (BaseException): The exception.
+        - tb (traceback): The traceback of the exception.
+
+        Returns:
+        - str: The traceback lines, each indented by four spaces.
+        """
+        lines = "".join(traceback.format_exception(exc_type, exc, tb)).rstrip("\n").splitlines()
+        return "\n".join(f"    {line}" for line in lines)
+
+    def catch(self, reraise=True, level="ERROR", **kwargs):
+        """
+        Log any exception raised in a function or a with block, with its traceback.
+        Use it as a decorator (@logly.catch()) or as a context manager (with logly.catch(): ...).
+
+        Parameters:
+        - reraise (bool, optional): Whether to raise the exception again after logging it. Defaults to True.
+        - level (str, optional): Log level used for the caught exceptions. Defaults to "ERROR".
+        - **kwargs: Any other option accepted by log_function (color, log_to_file, file_path, ...).
+
+        Returns:
+        - object: A decorator that can also be used as a context manager.
+        """
+        return _Catcher(self, level, reraise, kwargs)
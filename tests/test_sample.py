@@ -14,10 +14,15 @@ You should have received a copy of the MIT License
 along with Logly. If not, see <https://opensource.org/licenses/MIT>.
 """
 
+import contextlib
+import io
 import os
+import sys
 import pytest
+from datetime import datetime
 
 from logly import Logly
+from logly.exception import InvalidConfigError, InvalidLogLevelError, StreamWriteError
 
 @pytest.fixture
 def logly_instance():
@@ -91,3 +96,638 @@ def test_logly_integration(logly_instance):
         print(message)
 
 
+
+def test_total_bytes_written(logly_instance, tmp_path):
+    """
+    Test that total_bytes_written sums the bytes written to every log file.
+
+    Parameters:
+    - logly_instance (Logly): The Logly instance created by the fixture.
+    - tmp_path (Path): Temporary directory provided by pytest.
+    """
+    first = str(tmp_path / "first.txt")
+    second = str(tmp_path / "second.txt")
+    logly_instance.info("Key1", "Value1", file_path=first, show_time=False)
+    logly_instance.info("Key2", "Value2", file_path=second, show_time=False)
+
+    assert logly_instance.total_bytes_written() == os.path.getsize(first) + os.path.getsize(second)
+
+def test_add_stream(logly_instance):
+    """
+    Test that log messages are written to streams added with add_stream.
+
+    Parameters:
+    - logly_instance (Logly): The Logly instance created by the fixture.
+    """
+    stream = io.StringIO()
+    logly_instance.add_stream(stream)
+    logly_instance.info("StreamKey", "StreamValue", log_to_file=False, show_time=False, color_enabled=False)
+
+    assert stream.getvalue() == "INFO: StreamKey: StreamValue\n"
+    assert logly_instance.remove_stream(stream)
+    assert not logly_instance.remove_stream(stream)
+
+def test_set_test_clock(logly_instance):
+    """
+    Test that a pinned test clock makes log timestamps deterministic.
+
+    Parameters:
+    - logly_instance (Logly): The Logly instance created by the fixture.
+    """
+    stream = io.StringIO()
+    logly_instance.add_stream(stream)
+    logly_instance._set_test_clock(datetime(2024, 1, 2, 3, 4, 5))
+    logly_instance.info("ClockKey", "ClockValue", log_to_file=False, color_enabled=False)
+    logly_instance._set_test_clock(None)
+
+    assert stream.getvalue() == "[2024-01-02 03:04:05] INFO: ClockKey: ClockValue\n"
+
+def test_capture_stdout(logly_instance):
+    """
+    Test that print() output is logged line by line while stdout is captured.
+
+    Parameters:
+    - logly_instance (Logly): The Logly instance created by the fixture.
+    """
+    stream = io.StringIO()
+    logly_instance.add_stream(stream)
+    logly_instance.disable_file_logging()
+    logly_instance.capture_stdout(level="DEBUG")
+    try:
+        print("first line")
+        print("second", end="")
+    finally:
+        logly_instance.release_stdout()
+    logly_instance.enable_file_logging()
+
+    lines = stream.getvalue().splitlines()
+    assert len(lines) == 2
+    assert lines[0].endswith("DEBUG: None: first line")
+    assert lines[1].endswith("DEBUG: None: second")
+
+
+def test_capture_stdout_partial_line(logly_instance):
+    """
+    Test that flushing a partial line does not split it, that writelines is captured, and that other attributes
+    reach the original stdout.
+
+    Parameters:
+    - logly_instance (Logly): The Logly instance created by the fixture.
+    """
+    stream = io.StringIO()
+    logly_instance.add_stream(stream)
+    logly_instance.disable_file_logging()
+    original = sys.stdout
+    logly_instance.capture_stdout()
+    try:
+        print("progress", end="", flush=True)
+        print(" done")
+        sys.stdout.writelines(["via ", "writelines\n"])
+        assert sys.stdout.isatty() == original.isatty()
+        assert sys.stdout.encoding == getattr(original, "encoding", None)
+        assert getattr(sys.stdout, "buffer", None) is getattr(original, "buffer", None)
+    finally:
+        logly_instance.release_stdout()
+    logly_instance.enable_file_logging()
+
+    lines = stream.getvalue().splitlines()
+    assert len(lines) == 2
+    assert lines[0].endswith("INFO: None: progress done")
+    assert lines[1].endswith("INFO: None: via writelines")
+
+def test_info_throttled(logly_instance):
+    """
+    Test that a throttled message is only logged once within its interval.
+
+    Parameters:
+    - logly_instance (Logly): The Logly instance created by the fixture.
+    """
+    assert logly_instance.info_throttled("Disk nearly full", interval_ms=60000, log_to_file=False)
+    assert not logly_instance.info_throttled("Disk nearly full", interval_ms=60000, log_to_file=False)
+    assert logly_instance.info_throttled("Disk full", interval_ms=60000, log_to_file=False)
+    assert logly_instance.info_throttled("Disk nearly full", interval_ms=0, log_to_file=False)
+
+    assert logly_instance.warn_throttled("Disk slow", interval_ms=60000, log_to_file=False)
+    assert not logly_instance.log_throttled("warning", "Disk slow", interval_ms=60000, log_to_file=False)
+
+def test_set_level_color(logly_instance):
+    """
+    Test reading and updating the level color map.
+
+    Parameters:
+    - logly_instance (Logly): The Logly instance created by the fixture.
+    """
+    logly_instance.set_level_color("INFO", "red")
+    logly_instance.set_level_color("DEBUG", logly_instance.COLOR.WHITE)
+
+    colors = logly_instance.level_colors()
+    assert colors["INFO"] == logly_instance.COLOR.RED
+    assert colors["DEBUG"] == logly_instance.COLOR.WHITE
+    assert Logly.COLOR_MAP["INFO"] == logly_instance.COLOR.CYAN
+
+    with pytest.raises(InvalidConfigError):
+        logly_instance.set_level_color("INFO", "not-a-color")
+
+    logly_instance.set_level_color("warning", "green")
+    assert logly_instance.level_colors()["WARNING"] == logly_instance.COLOR.GREEN
+
+    with pytest.raises(InvalidLogLevelError):
+        logly_instance.set_level_color("LOUD", "red")
+
+def test_add_stream_when(logly_instance):
+    """
+    Test that a stream with a when predicate only receives the accepted messages.
+
+    Parameters:
+    - logly_instance (Logly): The Logly instance created by the fixture.
+    """
+    stream = io.StringIO()
+    logly_instance.add_stream(stream, when=lambda record: record["level"] == "ERROR")
+    logly_instance.info("InfoKey", "InfoValue", log_to_file=False)
+    logly_instance.error("ErrorKey", "ErrorValue", log_to_file=False)
+
+    assert "InfoKey" not in stream.getvalue()
+    assert "ErrorKey" in stream.getvalue()
+
+def test_show_time_relative():
+    """
+    Test that the console shows the time since the previous log message when show_time_relative is enabled.
+    """
+    logly = Logly(color_enabled=False, show_time_relative=True)
+    console = io.StringIO()
+    with contextlib.redirect_stdout(console):
+        logly._set_test_clock(datetime(2024, 1, 1, 12, 0, 0))
+        logly.info("First", "Value", log_to_file=False)
+        logly._set_test_clock(datetime(2024, 1, 1, 12, 0, 1, 500000))
+        logly.info("Second", "Value", log_to_file=False)
+
+    assert console.getvalue().splitlines() == ["[+0.000s] INFO: First: Value", "[+1.500s] INFO: Second: Value"]
+
+def test_set_level_aliases(logly_instance):
+    """
+    Test that aliased level names are logged as the level they map to.
+
+    Parameters:
+    - logly_instance (Logly): The Logly instance created by the fixture.
+    """
+    stream = io.StringIO()
+    logly_instance.add_stream(stream)
+    logly_instance.set_level_aliases({"FATAL": "CRITICAL", "notice": "info"})
+    logly_instance.fatal("FatalKey", "FatalValue", log_to_file=False, show_time=False, color_enabled=False)
+    logly_instance.log_function("NOTICE", "NoticeKey", "NoticeValue", log_to_file=False, show_time=False,
+                                color_enabled=False)
+
+    assert stream.getvalue().splitlines() == ["CRITICAL: FatalKey: FatalValue", "INFO: NoticeKey: NoticeValue"]
+
+def test_warn_once(logly_instance):
+    """
+    Test that warn_once only logs a message the first time it is seen.
+
+    Parameters:
+    - logly_instance (Logly): The Logly instance created by the fixture.
+    """
+    assert logly_instance.warn_once("deprecated API used", log_to_file=False)
+    assert not logly_instance.warn_once("deprecated API used", log_to_file=False)
+    assert logly_instance.log_once("INFO", "deprecated API used", log_to_file=False)
+    assert not logly_instance.log_once("warning", "deprecated API used", log_to_file=False)
+
+def test_set_max_line_bytes(logly_instance, tmp_path):
+    """
+    Test that lines written to a log file are cut to max_line_bytes without splitting UTF-8 characters.
+
+    Parameters:
+    - logly_instance (Logly): The Logly instance created by the fixture.
+    - tmp_path (Path): Temporary directory provided by pytest.
+    """
+    file_path = str(tmp_path / "long.txt")
+    logly_instance.set_max_line_bytes(40)
+    logly_instance.info("Key", "é" * 100, file_path=file_path, show_time=False, color_enabled=False)
+
+    with open(file_path, encoding="utf-8") as log_file:
+        line = log_file.read().rstrip("\n")
+    assert len(line.encode()) <= 40
+    assert line.startswith("INFO: Key: é")
+    assert line.endswith(logly_instance.TRUNCATION_MARKER)
+
+def test_set_record_separator(logly_instance):
+    """
+    Test that a custom record separator is written after each log message.
+
+    Parameters:
+    - logly_instance (Logly): The Logly instance created by the fixture.
+    """
+    stream = io.StringIO()
+    logly_instance.add_stream(stream)
+    logly_instance.set_record_separator("\0")
+    logly_instance.info("Trace", "line 1\nline 2", log_to_file=False, show_time=False, color_enabled=False)
+    logly_instance.info("Key", "Value", log_to_file=False, show_time=False, color_enabled=False)
+
+    assert stream.getvalue().split("\0") == ["INFO: Trace: line 1\nline 2", "INFO: Key: Value", ""]
+
+def test_metrics(logly_instance, tmp_path):
+    """
+    Test that metrics counts log messages, bytes, and dropped messages, and that reset_metrics zeroes them.
+
+    Parameters:
+    - logly_instance (Logly): The Logly instance created by the fixture.
+    - tmp_path (Path): Temporary directory provided by pytest.
+    """
+    file_path = str(tmp_path / "metrics.txt")
+    logly_instance.info("Key1", "Value1", file_path=file_path)
+    logly_instance.warn_throttled("Key2", "Value2", file_path=file_path)
+    logly_instance.warn_throttled("Key2", "Value2", file_path=file_path)
+
+    metrics = logly_instance.metrics()
+    assert metrics["total_logs"] == 2
+    assert metrics["bytes_written"] == os.path.getsize(file_path)
+    assert metrics["errors_count"] == 0
+    assert metrics["dropped_logs"] == 1
+
+    logly_instance.reset_metrics()
+    assert logly_instance.metrics() == {"total_logs": 0, "bytes_written": 0, "errors_count": 0, "dropped_logs": 0}
+
+def test_set_sample_rate(logly_instance):
+    """
+    Test that sampling drops messages below sample_min_level and keeps the ones at or above it.
+
+    Parameters:
+    - logly_instance (Logly): The Logly instance created by the fixture.
+    """
+    stream = io.StringIO()
+    logly_instance.add_stream(stream)
+    logly_instance.set_sample_rate(0.0, sample_min_level="ERROR")
+    for _ in range(10):
+        logly_instance.debug("DebugKey", "DebugValue", log_to_file=False)
+    logly_instance.error("ErrorKey", "ErrorValue", log_to_file=False)
+    logly_instance.set_sample_rate(None)
+    logly_instance.debug("DebugKey", "DebugValue", log_to_file=False)
+
+    assert len(stream.getvalue().splitlines()) == 2
+    assert logly_instance.metrics()["dropped_logs"] == 10
+
+    with pytest.raises(InvalidConfigError):
+        logly_instance.set_sample_rate(1.5)
+
+def test_set_debug_sample_rate(logly_instance):
+    """
+    Test that the debug sample rate lets DEBUG messages pass the minimum level.
+
+    Parameters:
+    - logly_instance (Logly): The Logly instance created by the fixture.
+    """
+    stream = io.StringIO()
+    logly_instance.add_stream(stream)
+    logly_instance.set_level("INFO")
+    logly_instance.debug("Skipped", "Value", log_to_file=False)
+    logly_instance.set_debug_sample_rate(1.0)
+    logly_instance.debug("Breadcrumb", "Value", log_to_file=False)
+    logly_instance.trace("Skipped", "Value", log_to_file=False)
+
+    assert len(stream.getvalue().splitlines()) == 1
+    assert "Breadcrumb" in stream.getvalue()
+
+    with pytest.raises(InvalidConfigError):
+        logly_instance.set_debug_sample_rate(-0.1)
+
+def test_register_level(logly_instance):
+    """
+    Test that a registered level is shown with its own name and color and sampled like its severity.
+
+    Parameters:
+    - logly_instance (Logly): The Logly instance created by the fixture.
+    """
+    stream = io.StringIO()
+    logly_instance.add_stream(stream)
+    logly_instance.register_level("notice", severity="INFO", color="GREEN")
+    logly_instance.register_level("AUDIT", severity="ERROR")
+    logly_instance.log_function("NOTICE", "NoticeKey", "NoticeValue", log_to_file=False, show_time=False,
+                                color_enabled=False)
+
+    assert stream.getvalue() == "NOTICE: NoticeKey: NoticeValue\n"
+    assert logly_instance.level_colors()["NOTICE"] == logly_instance.COLOR.GREEN
+    assert logly_instance.level_colors()["AUDIT"] == logly_instance.COLOR.RED
+    assert logly_instance.level_severity("NOTICE") == logly_instance.level_severity("INFO")
+
+    console = io.StringIO()
+    with contextlib.redirect_stdout(console):
+        logly_instance.log_function("notice", "LowerKey", "LowerValue", log_to_file=False, show_time=False)
+
+    assert console.getvalue().startswith(f" NOTICE: {logly_instance.COLOR.GREEN}LowerKey: LowerValue")
+    assert stream.getvalue().splitlines()[-1] == " NOTICE: LowerKey: LowerValue"
+
+def test_exception(logly_instance):
+    """
+    Test that exception logs the traceback of the exception being handled.
+
+    Parameters:
+    - logly_instance (Logly): The Logly instance created by the fixture.
+    """
+    stream = io.StringIO()
+    logly_instance.add_stream(stream)
+    try:
+        raise ValueError("bad value")
+    except ValueError:
+        logly_instance.exception("Parse", "failed", log_to_file=False, show_time=False, color_enabled=False)
+    logly_instance.exception("Parse", "failed", log_to_file=False, show_time=False, color_enabled=False)
+
+    lines = stream.getvalue().splitlines()
+    assert lines[0] == "ERROR: Parse: failed"
+    assert lines[1] == "    Traceback (most recent call last):"
+    assert "    ValueError: bad value" in lines
+    assert lines[-2:] == ["ERROR: Parse: failed", "    No exception was present"]
+
+def test_latency_stats(logly_instance):
+    """
+    Test that log call durations are only measured when enabled.
+
+    Parameters:
+    - logly_instance (Logly): The Logly instance created by the fixture.
+    """
+    logly_instance.info("Key", "Value", log_to_file=False)
+    assert logly_instance.latency_stats() == {"count": 0, "p50": None, "p95": None, "p99": None}
+
+    logly_instance.set_measure_latency(True)
+    for _ in range(5):
+        logly_instance.info("Key", "Value", log_to_file=False)
+
+    stats = logly_instance.latency_stats()
+    assert stats["count"] == 5
+    assert 0 < stats["p50"] <= stats["p95"] <= stats["p99"]
+
+def test_set_level(logly_instance):
+    """
+    Test that messages below the minimum level are skipped.
+
+    Parameters:
+    - logly_instance (Logly): The Logly instance created by the fixture.
+    """
+    stream = io.StringIO()
+    logly_instance.add_stream(stream)
+    assert logly_instance.get_level() is None
+
+    logly_instance.set_level("warning")
+    logly_instance.debug("DebugKey", "DebugValue", log_to_file=False)
+    logly_instance.info("InfoKey", "InfoValue", log_to_file=False)
+    logly_instance.error("ErrorKey", "ErrorValue", log_to_file=False)
+
+    assert logly_instance.get_level() == "WARNING"
+    assert len(stream.getvalue().splitlines()) == 1
+    assert "ErrorKey" in stream.getvalue()
+
+    with pytest.raises(InvalidLogLevelError):
+        logly_instance.set_level("LOUD")
+
+    logly_instance.set_level_aliases({"WARN": "WARNING"})
+    logly_instance.set_level("warn")
+    assert logly_instance.get_level() == "WARNING"
+
+def test_is_enabled(logly_instance):
+    """
+    Test that is_enabled follows the minimum level.
+
+    Parameters:
+    - logly_instance (Logly): The Logly instance created by the fixture.
+    """
+    assert logly_instance.is_enabled("DEBUG")
+
+    logly_instance.set_level("INFO")
+    logly_instance.set_level_aliases({"WARN": "WARNING"})
+    assert not logly_instance.is_enabled("DEBUG")
+    assert logly_instance.is_enabled("info")
+    assert logly_instance.is_enabled("WARN")
+
+def test_catch(logly_instance):
+    """
+    Test that catch logs exceptions as a decorator and as a context manager, and honours reraise.
+
+    Parameters:
+    - logly_instance (Logly): The Logly instance created by the fixture.
+    """
+    stream = io.StringIO()
+    logly_instance.add_stream(stream)
+
+    @logly_instance.catch(reraise=False, log_to_file=False, show_time=False, color_enabled=False)
+    def divide(a, b):
+        return a / b
+
+    assert divide(4, 2) == 2
+    assert divide(1, 0) is None
+    assert stream.getvalue().startswith("ERROR: Caught exception: ZeroDivisionError: division by zero\n")
+    assert "    Traceback (most recent call last):" in stream.getvalue()
+
+    with pytest.raises(KeyError):
+        with logly_instance.catch(level="CRITICAL", log_to_file=False):
+            {}["missing"]
+    assert "CRITICAL: " in stream.getvalue()
+
+def test_throttle_key(logly_instance):
+    """
+    Test that messages sharing a throttle key are throttled together even if their text differs.
+
+    Parameters:
+    - logly_instance (Logly): The Logly instance created by the fixture.
+    """
+    assert logly_instance.warn_throttled("disk slow", interval_ms=5000, throttle_key="disk", log_to_file=False)
+    assert not logly_instance.warn_throttled("disk very slow", interval_ms=5000, throttle_key="disk", log_to_file=False)
+    assert logly_instance.warn_throttled("disk slow", interval_ms=5000, throttle_key="network", log_to_file=False)
+    assert logly_instance.metrics()["dropped_logs"] == 1
+
+def test_add_memory_buffer(logly_instance):
+    """
+    Test that a memory buffer keeps only the most recent log messages.
+
+    Parameters:
+    - logly_instance (Logly): The Logly instance created by the fixture.
+    """
+    buffer = logly_instance.add_memory_buffer(capacity=2)
+    for number in range(3):
+        logly_instance.debug("Step", number, log_to_file=False, show_time=False, color_enabled=False)
+
+    assert buffer.dump() == ["DEBUG: Step: 1", "DEBUG: Step: 2"]
+
+    logly_instance.set_record_separator("\0")
+    logly_instance.debug("Step", 3, log_to_file=False, show_time=False, color_enabled=False)
+    assert buffer.dump() == ["DEBUG: Step: 2", "DEBUG: Step: 3"]
+    assert logly_instance.remove_stream(buffer)
+
+def test_log_batch(logly_instance):
+    """
+    Test that log_batch logs every record and logs nothing when a level is invalid.
+
+    Parameters:
+    - logly_instance (Logly): The Logly instance created by the fixture.
+    """
+    stream = io.StringIO()
+    logly_instance.add_stream(stream)
+    logly_instance.log_batch([("INFO", "Key1", "Value1"), ("error", "Value2")], log_to_file=False, show_time=False,
+                             color_enabled=False)
+    assert stream.getvalue().splitlines() == ["INFO: Key1: Value1", "ERROR: None: Value2"]
+
+    with pytest.raises(InvalidLogLevelError):
+        logly_instance.log_batch([("INFO", "Key3", "Value3"), ("LOUD", "Key4", "Value4")], log_to_file=False)
+    assert len(stream.getvalue().splitlines()) == 2
+
+    logly_instance.log_batch((("WARNING", f"Key{i}", f"Value{i}") for i in range(5, 7)), log_to_file=False,
+                             show_time=False, color_enabled=False)
+    assert stream.getvalue().splitlines()[2:] == ["WARNING: Key5: Value5", "WARNING: Key6: Value6"]
+
+def test_add_filter(logly_instance):
+    """
+    Test that filters can drop and change log messages, and that a failing filter is ignored.
+
+    Parameters:
+    - logly_instance (Logly): The Logly instance created by the fixture.
+    """
+    stream = io.StringIO()
+    logly_instance.add_stream(stream)
+    logly_instance.add_filter(lambda record: record["level"] != "DEBUG")
+    logly_instance.add_filter(lambda record: {"value": "***"} if record["key"] == "ssn" else None)
+    logly_instance.add_filter(lambda record: 1 / 0)
+
+    with pytest.warns(RuntimeWarning):
+        logly_instance.debug("DebugKey", "DebugValue", log_to_file=False, show_time=False, color_enabled=False)
+        logly_instance.info("ssn", "123-45-6789", log_to_file=False, show_time=False, color_enabled=False)
+
+    assert stream.getvalue() == "INFO: ssn: ***\n"
+    assert logly_instance.metrics()["dropped_logs"] == 1
+
+    failing_filter = logly_instance.filters[-1][0]
+    assert logly_instance.remove_filter(failing_filter)
+    assert not logly_instance.remove_filter(failing_filter)
+
+def test_set_redaction(logly_instance):
+    """
+    Test that redacted keys and pattern matches are masked.
+
+    Parameters:
+    - logly_instance (Logly): The Logly instance created by the fixture.
+    """
+    stream = io.StringIO()
+    logly_instance.add_stream(stream)
+    logly_instance.set_redaction(redact_keys=["Password"], redact_pattern=r"\d{16}")
+    logly_instance.info("password", "hunter2", log_to_file=False, show_time=False, color_enabled=False)
+    logly_instance.info("Payment", "card 4111111111111111 charged", log_to_file=False, show_time=False,
+                        color_enabled=False)
+
+    assert stream.getvalue().splitlines() == ["INFO: password: ***", "INFO: Payment: card *** charged"]
+
+def test_failing_stream(logly_instance, tmp_path):
+    """
+    Test that a failing stream does not keep the message from the other streams and the log file.
+
+    Parameters:
+    - logly_instance (Logly): The Logly instance created by the fixture.
+    - tmp_path (Path): Temporary directory provided by pytest.
+    """
+    class BrokenStream:
+        def write(self, text):
+            raise OSError("broken pipe")
+
+    stream = io.StringIO()
+    file_path = str(tmp_path / "failing.txt")
+    logly_instance.add_stream(BrokenStream())
+    logly_instance.add_stream(stream)
+
+    with pytest.raises(StreamWriteError):
+        logly_instance.info("Key", "Value", file_path=file_path)
+
+    assert "Key: Value" in stream.getvalue()
+    assert os.path.getsize(file_path) > 0
+    assert logly_instance.metrics()["errors_count"] == 1
+
+def test_add_stream_when_raises(logly_instance):
+    """
+    Test that a when predicate that raises only skips its own stream and warns once.
+
+    Parameters:
+    - logly_instance (Logly): The Logly instance created by the fixture.
+    """
+    def broken_when(record):
+        raise ValueError("broken predicate")
+
+    skipped = io.StringIO()
+    stream = io.StringIO()
+    logly_instance.add_stream(skipped, when=broken_when)
+    logly_instance.add_stream(stream)
+
+    with pytest.warns(RuntimeWarning):
+        logly_instance.info("Key", "Value", log_to_file=False)
+    logly_instance.info("Key2", "Value2", log_to_file=False)
+
+    assert skipped.getvalue() == ""
+    assert "Key: Value" in stream.getvalue()
+    assert "Key2: Value2" in stream.getvalue()
+    assert logly_instance.metrics()["errors_count"] == 2
+
+def test_raw(logly_instance):
+    """
+    Test that raw messages are written without the level and time prefix.
+
+    Parameters:
+    - logly_instance (Logly): The Logly instance created by the fixture.
+    """
+    stream = io.StringIO()
+    logly_instance.add_stream(stream)
+    logly_instance.info("Plain message", log_to_file=False, raw=True)
+    logly_instance.warn("Key", "Value", log_to_file=False, raw=True)
+    logly_instance.error("Key", "Value", log_to_file=False, show_time=False, color_enabled=False)
+
+    assert stream.getvalue().splitlines() == ["Plain message", "Key: Value", "ERROR: Key: Value"]
+
+def test_expect(logly_instance):
+    """
+    Test that expect logs an error only when the condition is false and returns the condition.
+
+    Parameters:
+    - logly_instance (Logly): The Logly instance created by the fixture.
+    """
+    stream = io.StringIO()
+    logly_instance.add_stream(stream)
+
+    assert logly_instance.expect(1 + 1 == 2, "Math", "still works", log_to_file=False) is True
+    assert stream.getvalue() == ""
+
+    assert logly_instance.expect([], "Queue", "is empty", log_to_file=False, show_time=False,
+                                 color_enabled=False) is False
+    assert stream.getvalue().splitlines() == ["ERROR: Queue: is empty"]
+
+def test_console_line_buffered():
+    """
+    Test that the console is flushed after every log message only when console_line_buffered is enabled.
+    """
+    class FlushSpy(io.StringIO):
+        flushes = 0
+
+        def flush(self):
+            self.flushes += 1
+            super().flush()
+
+    for line_buffered, expected_flushes in ((False, 0), (True, 2)):
+        logly = Logly(console_line_buffered=line_buffered)
+        console = FlushSpy()
+        with contextlib.redirect_stdout(console):
+            logly.info("Key1", "Value1", log_to_file=False)
+            logly.info("Key2", "Value2", log_to_file=False)
+
+        assert console.flushes == expected_flushes
+        assert "Key2" in console.getvalue()
+
+def test_set_console_encoding(logly_instance):
+    """
+    Test that set_console_encoding switches the console encoding and rejects unknown encodings.
+
+    Parameters:
+    - logly_instance (Logly): The Logly instance created by the fixture.
+    """
+    raw_console = io.BytesIO()
+    console = io.TextIOWrapper(raw_console, encoding="ascii")
+    with contextlib.redirect_stdout(console):
+        logly_instance.set_console_encoding("UTF8")
+        logly_instance.info("Greeting", "héllo wörld", log_to_file=False, show_time=False, color_enabled=False)
+    console.flush()
+
+    assert console.encoding == "utf-8"
+    assert raw_console.getvalue() == "INFO: Greeting: héllo wörld\n".encode("utf-8")
+
+    with pytest.raises(InvalidConfigError):
+        logly_instance.set_console_encoding("not-an-encoding")